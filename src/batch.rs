@@ -0,0 +1,53 @@
+//! Scaffolds several consecutive first-Wednesday folders in one run.
+
+use crate::cli::BatchArgs;
+use crate::odt;
+use std::fs;
+use std::path::Path;
+
+pub fn run(args: BatchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let (mut year, mut month) = odt::resolve_target_month(args.start_month);
+
+    let content_source = match &args.content_path {
+        Some(path) => Some(fs::read_to_string(path)?),
+        None => None,
+    };
+
+    let mut created = Vec::new();
+    let mut skipped = Vec::new();
+
+    for _ in 0..args.count {
+        let folder_name = odt::format_folder_name(odt::first_wednesday_of(year, month));
+
+        if Path::new(&folder_name).exists() {
+            println!("Skipping folder (already exists): {}", folder_name);
+            skipped.push(folder_name);
+        } else {
+            println!("Creating folder: {}", folder_name);
+            fs::create_dir_all(&folder_name)?;
+
+            let odt_path = Path::new(&folder_name).join("meeting.odt");
+            odt::create_odt_document(
+                &odt_path,
+                content_source.as_deref(),
+                &odt::DocumentMetadata::default(),
+            )?;
+            println!("Created ODT document: {}", odt_path.display());
+            created.push(folder_name);
+        }
+
+        month += 1;
+        if month > 12 {
+            month = 1;
+            year += 1;
+        }
+    }
+
+    println!(
+        "Batch complete: {} created, {} skipped",
+        created.len(),
+        skipped.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! Writes a companion iCalendar (.ics) file describing the first-Wednesday
+//! meeting date alongside the generated ODT document.
+
+use chrono::{NaiveDate, Utc};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Writes `<folder>/<event_date>.ics` containing a single all-day VEVENT for
+/// `event_date`, titled `summary`.
+pub fn write_ics_file(
+    folder: &Path,
+    event_date: NaiveDate,
+    summary: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dtstart = event_date.format("%Y%m%d").to_string();
+    let dtend = (event_date + chrono::Duration::days(1))
+        .format("%Y%m%d")
+        .to_string();
+    let dtstamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let uid = format!("{}@{}", dtstart, hostname());
+
+    let lines = [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//odt-cli-creator//EN".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", uid),
+        format!("DTSTAMP:{}", dtstamp),
+        format!("DTSTART;VALUE=DATE:{}", dtstart),
+        format!("DTEND;VALUE=DATE:{}", dtend),
+        format!("SUMMARY:{}", escape_ics_text(summary)),
+        "END:VEVENT".to_string(),
+        "END:VCALENDAR".to_string(),
+    ];
+
+    let mut ics = String::new();
+    for line in lines {
+        ics.push_str(&fold_line(&line));
+        ics.push_str("\r\n");
+    }
+
+    let ics_path = folder.join(format!("{}.ics", dtstart));
+    fs::write(ics_path, ics)?;
+    Ok(())
+}
+
+/// Folds a single logical line at 75 octets per RFC 5545 section 3.1,
+/// continuing with a single leading space on each wrapped line.
+fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+    let bytes = line.as_bytes();
+    if bytes.len() <= LIMIT {
+        return line.to_string();
+    }
+
+    let mut folded = String::new();
+    let mut start = 0;
+    let mut first = true;
+    while start < bytes.len() {
+        let budget = if first { LIMIT } else { LIMIT - 1 };
+        let mut end = (start + budget).min(bytes.len());
+        while end > start && !line.is_char_boundary(end) {
+            end -= 1;
+        }
+        if !first {
+            folded.push_str("\r\n ");
+        }
+        folded.push_str(&line[start..end]);
+        start = end;
+        first = false;
+    }
+    folded
+}
+
+/// Escapes commas, semicolons, and backslashes as required in ICS text
+/// values.
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+}
+
+fn hostname() -> String {
+    Command::new("hostname")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "localhost".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fold_line_leaves_short_lines_untouched() {
+        let line = "SUMMARY:short";
+        assert_eq!(fold_line(line), line);
+    }
+
+    #[test]
+    fn fold_line_wraps_long_lines_at_75_octets_with_leading_space() {
+        let line = format!("SUMMARY:{}", "x".repeat(100));
+        let folded = fold_line(&line);
+        let physical_lines: Vec<&str> = folded.split("\r\n").collect();
+        assert!(physical_lines.len() > 1);
+        for line in &physical_lines[1..] {
+            assert!(line.starts_with(' '));
+        }
+        for line in &physical_lines {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(
+            folded.replace("\r\n ", ""),
+            line,
+            "unfolding should reproduce the original line"
+        );
+    }
+
+    #[test]
+    fn escape_ics_text_escapes_commas_semicolons_and_backslashes() {
+        assert_eq!(escape_ics_text(r"a,b;c\d"), r"a\,b\;c\\d");
+    }
+}
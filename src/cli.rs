@@ -0,0 +1,215 @@
+//! Command line argument parsing.
+//!
+//! Parses `argv` into a [`Command`] rather than acting on it directly, so
+//! `main` can propagate parse failures as a `Result` and return a proper
+//! non-zero exit code instead of the parser calling `std::process::exit`
+//! itself.
+
+use std::fmt;
+
+/// A fully parsed invocation, ready to dispatch.
+pub enum Command {
+    /// Scaffold a folder and ODT document for one month (the original,
+    /// default behavior).
+    New(NewArgs),
+    /// Audit an existing ODT's package structure.
+    Validate(ValidateArgs),
+    /// Scaffold several consecutive months at once.
+    Batch(BatchArgs),
+    /// Print usage and exit successfully.
+    Help,
+}
+
+pub struct NewArgs {
+    pub month: Option<u32>,
+    pub content_path: Option<String>,
+    pub ics: bool,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub keywords: Vec<String>,
+}
+
+pub struct ValidateArgs {
+    pub path: String,
+}
+
+pub struct BatchArgs {
+    pub start_month: Option<u32>,
+    pub count: u32,
+    pub content_path: Option<String>,
+}
+
+/// An argument parsing failure, carrying a message `main` can print before
+/// exiting non-zero.
+#[derive(Debug)]
+pub struct CliError(pub String);
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Parses `argv` (including the program name at index 0) into a [`Command`].
+///
+/// A first argument that is a known subcommand (`new`, `validate`, `batch`)
+/// selects it; a first argument that looks like a flag, or no arguments at
+/// all, defaults to `new` for backwards compatibility with versions of this
+/// tool that had no subcommands.
+pub fn parse(args: &[String]) -> Result<Command, CliError> {
+    match args.get(1).map(String::as_str) {
+        Some("--help") | Some("-h") => Ok(Command::Help),
+        Some("new") => parse_new(&args[2..]),
+        Some("validate") => parse_validate(&args[2..]),
+        Some("batch") => parse_batch(&args[2..]),
+        Some(arg) if arg.starts_with('-') => parse_new(&args[1..]),
+        None => parse_new(&[]),
+        Some(other) => Err(CliError(format!("Unknown subcommand: {}", other))),
+    }
+}
+
+fn parse_new(args: &[String]) -> Result<Command, CliError> {
+    let mut month = None;
+    let mut content_path = None;
+    let mut ics = false;
+    let mut title = None;
+    let mut author = None;
+    let mut keywords = Vec::new();
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--month" | "-m" => {
+                month = Some(require_month(args, &mut i)?);
+            }
+            "--content" => {
+                content_path = Some(require_value(args, &mut i, "--content")?);
+            }
+            "--ics" => {
+                ics = true;
+                i += 1;
+            }
+            "--title" => {
+                title = Some(require_value(args, &mut i, "--title")?);
+            }
+            "--author" => {
+                author = Some(require_value(args, &mut i, "--author")?);
+            }
+            "--keyword" => {
+                keywords.push(require_value(args, &mut i, "--keyword")?);
+            }
+            "--help" | "-h" => return Ok(Command::Help),
+            other => return Err(CliError(format!("Unknown option: {}", other))),
+        }
+    }
+
+    Ok(Command::New(NewArgs {
+        month,
+        content_path,
+        ics,
+        title,
+        author,
+        keywords,
+    }))
+}
+
+fn parse_validate(args: &[String]) -> Result<Command, CliError> {
+    let path = args
+        .first()
+        .ok_or_else(|| CliError("validate requires a path to an .odt file".to_string()))?
+        .clone();
+
+    Ok(Command::Validate(ValidateArgs { path }))
+}
+
+fn parse_batch(args: &[String]) -> Result<Command, CliError> {
+    let mut start_month = None;
+    let mut count = None;
+    let mut content_path = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--month" | "-m" => {
+                start_month = Some(require_month(args, &mut i)?);
+            }
+            "--count" | "-n" => {
+                let value = require_value(args, &mut i, "--count/-n")?;
+                count = Some(value.parse::<u32>().ok().filter(|&n| n > 0).ok_or_else(|| {
+                    CliError(format!("--count/-n must be a positive number, got: {}", value))
+                })?);
+            }
+            "--content" => {
+                content_path = Some(require_value(args, &mut i, "--content")?);
+            }
+            other => return Err(CliError(format!("Unknown option: {}", other))),
+        }
+    }
+
+    let count = count.ok_or_else(|| CliError("batch requires --count/-n <N>".to_string()))?;
+
+    Ok(Command::Batch(BatchArgs {
+        start_month,
+        count,
+        content_path,
+    }))
+}
+
+/// Reads the value following `args[*i]`, advancing `*i` past both, and
+/// parses it as a month (1-12).
+fn require_month(args: &[String], i: &mut usize) -> Result<u32, CliError> {
+    let value = require_value(args, i, "--month/-m")?;
+    match value.parse::<u32>() {
+        Ok(m) if (1..=12).contains(&m) => Ok(m),
+        _ => Err(CliError(format!(
+            "Month must be a number between 1 and 12, got: {}",
+            value
+        ))),
+    }
+}
+
+/// Reads the value following `args[*i]`, advancing `*i` past both.
+fn require_value(args: &[String], i: &mut usize, flag: &str) -> Result<String, CliError> {
+    let value = args
+        .get(*i + 1)
+        .ok_or_else(|| CliError(format!("{} requires a value", flag)))?
+        .clone();
+    *i += 2;
+    Ok(value)
+}
+
+pub fn print_usage() {
+    println!("Usage: odt_creator <COMMAND> [OPTIONS]");
+    println!();
+    println!("Commands:");
+    println!("  new       Scaffold a folder and ODT document for one month (default)");
+    println!("  validate  Audit an existing ODT's package structure");
+    println!("  batch     Scaffold several consecutive months at once");
+    println!();
+    println!("'new' options:");
+    println!("  -m, --month <MONTH>    Specify the month (1-12) for which to create the folder");
+    println!("                         If not specified, uses the following month");
+    println!("      --content <FILE>   Populate the document from a Markdown file (or stdin)");
+    println!("      --ics              Also write a companion .ics calendar event");
+    println!("      --title <TITLE>    Set the document's dc:title");
+    println!("      --author <NAME>    Set the document's initial creator / dc:creator");
+    println!("      --keyword <WORD>   Add a meta:keyword (repeatable)");
+    println!();
+    println!("'validate' options:");
+    println!("  validate <FILE.odt>    Path to the ODT file to audit");
+    println!();
+    println!("'batch' options:");
+    println!("  -m, --month <MONTH>    Month to start from (defaults like 'new')");
+    println!("  -n, --count <N>        Number of consecutive months to create");
+    println!("      --content <FILE>   Populate each document from a Markdown file");
+    println!();
+    println!("  -h, --help             Show this help message");
+    println!();
+    println!("Examples:");
+    println!("  odt_creator                        # Creates folder for next month's first Wednesday");
+    println!("  odt_creator new -m 9                # Creates folder for September's first Wednesday");
+    println!("  odt_creator validate 20260902/notes.odt");
+    println!("  odt_creator batch -m 1 -n 3          # Creates the next 3 months starting in January");
+}
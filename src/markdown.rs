@@ -0,0 +1,261 @@
+//! Converts a Markdown source document into ODT body content.
+//!
+//! This is a deliberately small Markdown subset: headings, paragraphs,
+//! unordered lists, and `**bold**`/`*italic*` inline spans. It is enough to
+//! turn a user's notes into real `content.xml` body markup instead of the
+//! single hardcoded placeholder paragraph the tool used to emit.
+
+/// The generated body markup plus the automatic styles it references.
+pub struct ConvertedContent {
+    pub body_xml: String,
+    pub automatic_styles_xml: String,
+}
+
+/// Parses `source` as Markdown and renders the equivalent ODT body XML.
+pub fn convert(source: &str) -> ConvertedContent {
+    let mut body = String::new();
+    let mut uses_heading = [false; 3];
+    let mut uses_bold = false;
+    let mut uses_italic = false;
+
+    for block in split_blocks(source) {
+        let first_line = block[0].trim_start();
+        if let Some((level, text)) = heading(first_line) {
+            uses_heading[level - 1] = true;
+            body.push_str(&format!(
+                "            <text:h text:outline-level=\"{level}\" text:style-name=\"Heading_{level}\">{}</text:h>\n",
+                render_inline(text, &mut uses_bold, &mut uses_italic)
+            ));
+            if block.len() > 1 {
+                // The heading wasn't blank-line-separated from what follows
+                // it; render the rest of the block instead of dropping it,
+                // as a list if that's what it is, otherwise as a paragraph.
+                let rest = &block[1..];
+                if rest.iter().all(|line| is_list_item(line.trim_start())) {
+                    render_list(rest, &mut body, &mut uses_bold, &mut uses_italic);
+                } else {
+                    let paragraph_text = rest.join(" ");
+                    body.push_str(&format!(
+                        "            <text:p text:style-name=\"Standard\">{}</text:p>\n",
+                        render_inline(&paragraph_text, &mut uses_bold, &mut uses_italic)
+                    ));
+                }
+            }
+        } else if block.iter().all(|line| is_list_item(line.trim_start())) {
+            render_list(&block, &mut body, &mut uses_bold, &mut uses_italic);
+        } else {
+            let paragraph_text = block.join(" ");
+            body.push_str(&format!(
+                "            <text:p text:style-name=\"Standard\">{}</text:p>\n",
+                render_inline(&paragraph_text, &mut uses_bold, &mut uses_italic)
+            ));
+        }
+    }
+
+    ConvertedContent {
+        body_xml: body,
+        automatic_styles_xml: automatic_styles(&uses_heading, uses_bold, uses_italic),
+    }
+}
+
+/// Splits `source` into blocks separated by one or more blank lines.
+fn split_blocks(source: &str) -> Vec<Vec<String>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+
+    for line in source.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line.to_string());
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+/// Returns the heading level (1-3) and remaining text if `line` is a heading.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    for level in (1..=3).rev() {
+        let prefix = "#".repeat(level) + " ";
+        if let Some(text) = line.strip_prefix(&prefix) {
+            return Some((level, text));
+        }
+    }
+    None
+}
+
+fn is_list_item(line: &str) -> bool {
+    line.starts_with("- ") || line.starts_with("* ")
+}
+
+fn list_item_text(line: &str) -> &str {
+    &line[2..]
+}
+
+/// Renders `lines` (all already confirmed to be list items) as a single
+/// `<text:list>`, appending the markup to `body`.
+fn render_list(lines: &[String], body: &mut String, uses_bold: &mut bool, uses_italic: &mut bool) {
+    body.push_str("            <text:list text:style-name=\"ListStyle\">\n");
+    for line in lines {
+        let item_text = list_item_text(line.trim_start());
+        body.push_str(&format!(
+            "                <text:list-item><text:p text:style-name=\"Standard\">{}</text:p></text:list-item>\n",
+            render_inline(item_text, uses_bold, uses_italic)
+        ));
+    }
+    body.push_str("            </text:list>\n");
+}
+
+/// Renders inline `**bold**`/`*italic*` spans and escapes the remaining text.
+fn render_inline(text: &str, uses_bold: &mut bool, uses_italic: &mut bool) -> String {
+    let mut out = String::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '*' && chars.get(i + 1) == Some(&'*') {
+            if let Some(end) = find_closing(&chars, i + 2, "**") {
+                let inner: String = chars[i + 2..end].iter().collect();
+                *uses_bold = true;
+                out.push_str("<text:span text:style-name=\"Bold_Text\">");
+                out.push_str(&escape_xml(&inner));
+                out.push_str("</text:span>");
+                i = end + 2;
+                continue;
+            }
+        } else if chars[i] == '*' {
+            if let Some(end) = find_closing(&chars, i + 1, "*") {
+                let inner: String = chars[i + 1..end].iter().collect();
+                *uses_italic = true;
+                out.push_str("<text:span text:style-name=\"Italic_Text\">");
+                out.push_str(&escape_xml(&inner));
+                out.push_str("</text:span>");
+                i = end + 1;
+                continue;
+            }
+        }
+        out.push_str(&escape_xml(&chars[i].to_string()));
+        i += 1;
+    }
+
+    out
+}
+
+/// Finds the index of `marker` starting at or after `from`, returning the
+/// index of its first character.
+fn find_closing(chars: &[char], from: usize, marker: &str) -> Option<usize> {
+    let marker: Vec<char> = marker.chars().collect();
+    let mut i = from;
+    while i + marker.len() <= chars.len() {
+        if chars[i..i + marker.len()] == marker[..] {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Escapes the four XML special characters required inside text content and
+/// attribute values.
+pub fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn automatic_styles(uses_heading: &[bool; 3], uses_bold: bool, uses_italic: bool) -> String {
+    let mut styles = String::new();
+
+    for (index, used) in uses_heading.iter().enumerate() {
+        if *used {
+            let level = index + 1;
+            let font_size = match level {
+                1 => "24pt",
+                2 => "20pt",
+                _ => "16pt",
+            };
+            styles.push_str(&format!(
+                "        <style:style style:name=\"Heading_{level}\" style:family=\"paragraph\" style:class=\"text\">\n            <style:text-properties fo:font-weight=\"bold\" fo:font-size=\"{font_size}\"/>\n        </style:style>\n"
+            ));
+        }
+    }
+
+    if uses_bold {
+        styles.push_str("        <style:style style:name=\"Bold_Text\" style:family=\"text\">\n            <style:text-properties fo:font-weight=\"bold\"/>\n        </style:style>\n");
+    }
+
+    if uses_italic {
+        styles.push_str("        <style:style style:name=\"Italic_Text\" style:family=\"text\">\n            <style:text-properties fo:font-style=\"italic\"/>\n        </style:style>\n");
+    }
+
+    styles.push_str("        <text:list-style style:name=\"ListStyle\">\n            <text:list-level-style-bullet text:level=\"1\" text:bullet-char=\"•\"/>\n        </text:list-style>\n");
+
+    styles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heading_blank_line_separated_from_paragraph() {
+        let converted = convert("# Title\n\nA paragraph.\n");
+        assert!(converted.body_xml.contains("<text:h text:outline-level=\"1\""));
+        assert!(converted.body_xml.contains("<text:p text:style-name=\"Standard\">A paragraph.</text:p>"));
+    }
+
+    #[test]
+    fn heading_immediately_followed_by_text_keeps_both() {
+        let converted = convert("# Meeting Notes\nThis is the body text that follows immediately.\n");
+        assert!(converted.body_xml.contains("Meeting Notes</text:h>"));
+        assert!(converted
+            .body_xml
+            .contains("This is the body text that follows immediately."));
+    }
+
+    #[test]
+    fn heading_immediately_followed_by_list_renders_as_list() {
+        let converted = convert("## Agenda\n- one\n- two\n");
+        assert!(converted.body_xml.contains("Agenda</text:h>"));
+        assert_eq!(converted.body_xml.matches("<text:list ").count(), 1);
+        assert_eq!(converted.body_xml.matches("<text:list-item>").count(), 2);
+        assert!(!converted.body_xml.contains("- one - two"));
+    }
+
+    #[test]
+    fn list_collapses_into_single_text_list() {
+        let converted = convert("- one\n- two\n");
+        assert_eq!(converted.body_xml.matches("<text:list ").count(), 1);
+        assert_eq!(converted.body_xml.matches("<text:list-item>").count(), 2);
+    }
+
+    #[test]
+    fn inline_bold_and_italic_spans() {
+        let converted = convert("Some **bold** and *italic* text.\n");
+        assert!(converted
+            .body_xml
+            .contains("<text:span text:style-name=\"Bold_Text\">bold</text:span>"));
+        assert!(converted
+            .body_xml
+            .contains("<text:span text:style-name=\"Italic_Text\">italic</text:span>"));
+    }
+
+    #[test]
+    fn escape_xml_escapes_all_four_special_characters() {
+        assert_eq!(escape_xml("&<>\"'"), "&amp;&lt;&gt;&quot;'");
+    }
+}
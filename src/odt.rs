@@ -0,0 +1,212 @@
+//! Core ODT package assembly: computing the target folder name and writing
+//! the zip-based OpenDocument Text file itself.
+
+use crate::markdown;
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use zip::{write::FileOptions, ZipWriter};
+
+/// Computes the first Wednesday of `year`-`month`.
+pub fn first_wednesday_of(year: i32, month: u32) -> NaiveDate {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+    let days_until_wednesday = match first_day.weekday() {
+        Weekday::Wed => 0,
+        Weekday::Thu => 6,
+        Weekday::Fri => 5,
+        Weekday::Sat => 4,
+        Weekday::Sun => 3,
+        Weekday::Mon => 2,
+        Weekday::Tue => 1,
+    };
+    first_day + chrono::Duration::days(days_until_wednesday)
+}
+
+/// Resolves a user-specified (or absent) target month against today's date,
+/// the way the `new` and `batch` commands both pick a starting month:
+/// an explicit month already passed this year rolls over to next year, and
+/// no month at all means "next month".
+pub fn resolve_target_month(target_month: Option<u32>) -> (i32, u32) {
+    let today = Local::now().date_naive();
+    let current_year = today.year();
+
+    match target_month {
+        Some(m) if m < today.month() => (current_year + 1, m),
+        Some(m) => (current_year, m),
+        None if today.month() == 12 => (current_year + 1, 1),
+        None => (current_year, today.month() + 1),
+    }
+}
+
+/// Computes the `YYYYMMDD` folder name for the first Wednesday of
+/// `target_month`, or of next month if `target_month` is `None`.
+pub fn get_first_wednesday_for_month(target_month: Option<u32>) -> String {
+    let (year, month) = resolve_target_month(target_month);
+    format_folder_name(first_wednesday_of(year, month))
+}
+
+/// Formats a date as the `YYYYMMDD` folder name this tool uses.
+pub fn format_folder_name(date: NaiveDate) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+/// Document metadata supplied via `--title`, `--author`, and `--keyword`,
+/// written into `meta.xml`'s `dc:title`/`meta:initial-creator`/`dc:creator`/
+/// `meta:keyword` elements.
+#[derive(Default)]
+pub struct DocumentMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub keywords: Vec<String>,
+}
+
+pub fn create_odt_document(
+    path: &Path,
+    content_source: Option<&str>,
+    metadata: &DocumentMetadata,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file = fs::File::create(path)?;
+    let mut zip = ZipWriter::new(file);
+
+    // Add mimetype (must be first and uncompressed)
+    zip.start_file(
+        "mimetype",
+        FileOptions::default().compression_method(zip::CompressionMethod::Stored),
+    )?;
+    zip.write_all(b"application/vnd.oasis.opendocument.text")?;
+
+    // Add META-INF/manifest.xml
+    zip.start_file("META-INF/manifest.xml", FileOptions::default())?;
+    let manifest = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0">
+    <manifest:file-entry manifest:full-path="/" manifest:media-type="application/vnd.oasis.opendocument.text"/>
+    <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+    <manifest:file-entry manifest:full-path="styles.xml" manifest:media-type="text/xml"/>
+    <manifest:file-entry manifest:full-path="meta.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>"#;
+    zip.write_all(manifest.as_bytes())?;
+
+    // Add content.xml
+    zip.start_file("content.xml", FileOptions::default())?;
+    let (body_xml, automatic_styles_xml) = match content_source {
+        Some(source) => {
+            let converted = markdown::convert(source);
+            (converted.body_xml, converted.automatic_styles_xml)
+        }
+        None => (
+            "            <text:p text:style-name=\"Standard\">This is a new ODT document created by Rust CLI tool.</text:p>\n".to_string(),
+            String::new(),
+        ),
+    };
+    let content = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0"
+                        xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0"
+                        xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0"
+                        xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0">
+    <office:automatic-styles>
+{automatic_styles_xml}    </office:automatic-styles>
+    <office:body>
+        <office:text>
+{body_xml}        </office:text>
+    </office:body>
+</office:document-content>"#
+    );
+    zip.write_all(content.as_bytes())?;
+
+    // Add styles.xml
+    zip.start_file("styles.xml", FileOptions::default())?;
+    let styles = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-styles xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0"
+                       xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0"
+                       xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0"
+                       xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0">
+    <office:styles>
+        <style:default-style style:family="paragraph">
+            <style:paragraph-properties fo:hyphenation-ladder-count="no-limit"/>
+            <style:text-properties fo:language="en" fo:country="US"/>
+        </style:default-style>
+        <style:style style:name="Standard" style:family="paragraph" style:class="text"/>
+    </office:styles>
+</office:document-styles>"#;
+    zip.write_all(styles.as_bytes())?;
+
+    // Add meta.xml
+    zip.start_file("meta.xml", FileOptions::default())?;
+    let creation_date = Local::now().format("%Y-%m-%dT%H:%M:%S");
+
+    let mut meta_fields = String::new();
+    if let Some(title) = &metadata.title {
+        meta_fields.push_str(&format!(
+            "        <dc:title>{}</dc:title>\n",
+            markdown::escape_xml(title)
+        ));
+    }
+    if let Some(author) = &metadata.author {
+        let escaped = markdown::escape_xml(author);
+        meta_fields.push_str(&format!(
+            "        <meta:initial-creator>{escaped}</meta:initial-creator>\n        <dc:creator>{escaped}</dc:creator>\n"
+        ));
+    }
+    for keyword in &metadata.keywords {
+        meta_fields.push_str(&format!(
+            "        <meta:keyword>{}</meta:keyword>\n",
+            markdown::escape_xml(keyword)
+        ));
+    }
+
+    let meta = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-meta xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0"
+                     xmlns:meta="urn:oasis:names:tc:opendocument:xmlns:meta:1.0"
+                     xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <office:meta>
+        <meta:generator>Rust CLI ODT Creator</meta:generator>
+        <meta:creation-date>{creation_date}</meta:creation-date>
+{meta_fields}    </office:meta>
+</office:document-meta>"#
+    );
+    zip.write_all(meta.as_bytes())?;
+
+    zip.finish()?;
+    Ok(())
+}
+
+pub fn open_document(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let path_str = path.to_string_lossy();
+
+    // Try different commands based on the operating system
+    let result = if cfg!(target_os = "windows") {
+        Command::new("cmd")
+            .args(["/C", "start", "", &path_str])
+            .spawn()
+    } else if cfg!(target_os = "macos") {
+        Command::new("open").arg(&path_str.to_string()).spawn()
+    } else {
+        // Linux and other Unix-like systems
+        // Try LibreOffice first, then OpenOffice, then xdg-open
+        Command::new("libreoffice")
+            .arg(&path_str.to_string())
+            .spawn()
+            .or_else(|_| {
+                Command::new("openoffice")
+                    .arg(&path_str.to_string())
+                    .spawn()
+            })
+            .or_else(|_| Command::new("xdg-open").arg(&path_str.to_string()).spawn())
+    };
+
+    match result {
+        Ok(_) => {
+            println!("Opening document with default application...");
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("Warning: Could not open document automatically: {}", e);
+            eprintln!("Please open the file manually: {}", path_str);
+            Ok(())
+        }
+    }
+}
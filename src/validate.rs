@@ -0,0 +1,214 @@
+//! Audits an existing ODT file's package structure: the well-known parts,
+//! the `mimetype` entry's placement and storage, and the manifest's
+//! agreement with the zip's actual contents.
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+
+const EXPECTED_MIMETYPE: &str = "application/vnd.oasis.opendocument.text";
+const REQUIRED_PARTS: &[&str] = &["META-INF/manifest.xml", "content.xml", "styles.xml", "meta.xml"];
+
+/// Entries that are part of the package but, by convention (and by how this
+/// tool's own `create_odt_document` writes manifests), are not themselves
+/// listed as `manifest:file-entry` elements.
+const MANIFEST_EXEMPT: &[&str] = &["mimetype", "META-INF/manifest.xml"];
+
+/// Opens `path` as a zip archive and checks that it is a well-formed
+/// OpenDocument package, printing every discrepancy found and returning an
+/// error if any exist.
+pub fn run(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::open(Path::new(path))?;
+    let mut archive = ZipArchive::new(file)?;
+
+    let mut problems = Vec::new();
+    check_mimetype_entry(&mut archive, &mut problems)?;
+
+    let entry_names: HashSet<String> = (0..archive.len())
+        .map(|i| Ok(archive.by_index(i)?.name().to_string()))
+        .collect::<Result<_, zip::result::ZipError>>()?;
+
+    for required in REQUIRED_PARTS {
+        if !entry_names.contains(*required) {
+            problems.push(format!("missing required part: {}", required));
+        }
+    }
+
+    if entry_names.contains("META-INF/manifest.xml") {
+        let manifest_xml = {
+            let mut entry = archive.by_name("META-INF/manifest.xml")?;
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents)?;
+            contents
+        };
+        check_manifest_agreement(&manifest_xml, &entry_names, &mut problems);
+    }
+
+    if problems.is_empty() {
+        println!("{}: looks like a well-formed ODT package", path);
+        Ok(())
+    } else {
+        for problem in &problems {
+            eprintln!("{}: {}", path, problem);
+        }
+        Err(format!("{} failed validation ({} issue(s))", path, problems.len()).into())
+    }
+}
+
+/// Checks that the first archive entry is `mimetype`, stored uncompressed,
+/// and holds the expected media type.
+fn check_mimetype_entry(
+    archive: &mut ZipArchive<File>,
+    problems: &mut Vec<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if archive.is_empty() {
+        problems.push("package is empty".to_string());
+        return Ok(());
+    }
+
+    let mut entry = archive.by_index(0)?;
+    if entry.name() != "mimetype" {
+        problems.push(format!(
+            "first archive entry must be named 'mimetype', found: {}",
+            entry.name()
+        ));
+        return Ok(());
+    }
+
+    if entry.compression() != zip::CompressionMethod::Stored {
+        problems.push("'mimetype' entry must be stored uncompressed".to_string());
+    }
+
+    let mut contents = String::new();
+    entry.read_to_string(&mut contents)?;
+    if contents != EXPECTED_MIMETYPE {
+        problems.push(format!(
+            "'mimetype' entry must contain '{}', found: '{}'",
+            EXPECTED_MIMETYPE, contents
+        ));
+    }
+
+    Ok(())
+}
+
+/// Cross-checks the manifest's `manifest:file-entry` paths against the
+/// zip's actual entries in both directions.
+fn check_manifest_agreement(
+    manifest_xml: &str,
+    entry_names: &HashSet<String>,
+    problems: &mut Vec<String>,
+) {
+    let manifest_paths: HashSet<String> = extract_manifest_paths(manifest_xml)
+        .into_iter()
+        .filter(|path| path != "/")
+        .collect();
+
+    for path in &manifest_paths {
+        if !entry_names.contains(path) {
+            problems.push(format!(
+                "manifest references '{}' but it is not in the package",
+                path
+            ));
+        }
+    }
+
+    for name in entry_names {
+        if MANIFEST_EXEMPT.contains(&name.as_str()) {
+            continue;
+        }
+        if !manifest_paths.contains(name) {
+            problems.push(format!(
+                "package contains '{}' but the manifest does not list it",
+                name
+            ));
+        }
+    }
+}
+
+/// Extracts every `manifest:full-path="..."` attribute value from a
+/// `manifest.xml` document.
+fn extract_manifest_paths(manifest_xml: &str) -> Vec<String> {
+    const ATTR: &str = "manifest:full-path=\"";
+    let mut paths = Vec::new();
+    let mut rest = manifest_xml;
+
+    while let Some(start) = rest.find(ATTR) {
+        rest = &rest[start + ATTR.len()..];
+        if let Some(end) = rest.find('"') {
+            paths.push(rest[..end].to_string());
+            rest = &rest[end + 1..];
+        } else {
+            break;
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_manifest_paths_reads_every_full_path_attribute() {
+        let manifest = r#"<manifest:manifest>
+            <manifest:file-entry manifest:full-path="/" manifest:media-type="application/vnd.oasis.opendocument.text"/>
+            <manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+        </manifest:manifest>"#;
+
+        assert_eq!(
+            extract_manifest_paths(manifest),
+            vec!["/".to_string(), "content.xml".to_string()]
+        );
+    }
+
+    #[test]
+    fn check_manifest_agreement_flags_entry_missing_from_package() {
+        let manifest = r#"<manifest:file-entry manifest:full-path="meta.xml"/>"#;
+        let entry_names: HashSet<String> = HashSet::new();
+        let mut problems = Vec::new();
+
+        check_manifest_agreement(manifest, &entry_names, &mut problems);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("meta.xml"));
+        assert!(problems[0].contains("not in the package"));
+    }
+
+    #[test]
+    fn check_manifest_agreement_flags_entry_missing_from_manifest() {
+        let manifest = "";
+        let entry_names: HashSet<String> = ["content.xml".to_string()].into_iter().collect();
+        let mut problems = Vec::new();
+
+        check_manifest_agreement(manifest, &entry_names, &mut problems);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("content.xml"));
+        assert!(problems[0].contains("does not list it"));
+    }
+
+    #[test]
+    fn check_manifest_agreement_exempts_mimetype_and_manifest_itself() {
+        let manifest = "";
+        let entry_names: HashSet<String> = MANIFEST_EXEMPT.iter().map(|s| s.to_string()).collect();
+        let mut problems = Vec::new();
+
+        check_manifest_agreement(manifest, &entry_names, &mut problems);
+
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn check_manifest_agreement_ignores_the_root_slash_entry() {
+        let manifest = r#"<manifest:file-entry manifest:full-path="/"/>"#;
+        let entry_names: HashSet<String> = HashSet::new();
+        let mut problems = Vec::new();
+
+        check_manifest_agreement(manifest, &entry_names, &mut problems);
+
+        assert!(problems.is_empty());
+    }
+}